@@ -1,8 +1,71 @@
 /// Clamps a value between a minimum and maximum.
-pub fn clamp(value: i32, min: i32, max: i32) -> i32 {
+///
+/// Panics if `min > max`.
+pub fn clamp<T: Ord>(value: T, min: T, max: T) -> T {
+    assert!(min <= max, "min must be <= max");
     if value < min {
         min
-    } else if value >= max {  // Bug: should be > not >=
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Extension trait adding a clamping method to any `Ord` type.
+///
+/// Named `clamped` rather than `clamp` because `Ord::clamp` is itself a
+/// stable std method with the same semantics, and reusing that name would
+/// make every call ambiguous for types that are `Ord`.
+pub trait ClampExt: Ord + Sized {
+    fn clamped(self, min: Self, max: Self) -> Self;
+}
+
+impl<T: Ord> ClampExt for T {
+    fn clamped(self, min: Self, max: Self) -> Self {
+        clamp(self, min, max)
+    }
+}
+
+/// Clamps a value to an inclusive range, e.g. `clamp_range(value, min..=max)`.
+///
+/// Delegates to [`clamp`], so the same `T: Ord` bound covers `Wrapping<i32>`,
+/// `Wrapping<i64>`, and any other `Ord` type.
+pub fn clamp_range<T: Ord>(value: T, range: std::ops::RangeInclusive<T>) -> T {
+    let (min, max) = range.into_inner();
+    clamp(value, min, max)
+}
+
+/// Clamps an `f64`, propagating `NaN` and handling infinities correctly.
+///
+/// Panics if `min > max`, or if `min` or `max` is `NaN`.
+pub fn clamp_f64(value: f64, min: f64, max: f64) -> f64 {
+    assert!(!min.is_nan(), "min must not be NaN");
+    assert!(!max.is_nan(), "max must not be NaN");
+    assert!(min <= max, "min must be <= max");
+    if value.is_nan() {
+        value
+    } else if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Clamps an `f32`, propagating `NaN` and handling infinities correctly.
+///
+/// Panics if `min > max`, or if `min` or `max` is `NaN`.
+pub fn clamp_f32(value: f32, min: f32, max: f32) -> f32 {
+    assert!(!min.is_nan(), "min must not be NaN");
+    assert!(!max.is_nan(), "max must not be NaN");
+    assert!(min <= max, "min must be <= max");
+    if value.is_nan() {
+        value
+    } else if value < min {
+        min
+    } else if value > max {
         max
     } else {
         value
@@ -27,9 +90,108 @@ pub fn fibonacci(n: u32) -> u64 {
     b
 }
 
+/// Returns the nth Fibonacci number, or `None` on overflow instead of wrapping.
+///
+/// `fibonacci` overflows past `n = 93` (panicking in debug builds, wrapping in
+/// release builds); this is the overflow-safe alternative.
+pub fn checked_fibonacci(n: u32) -> Option<u64> {
+    if n == 0 {
+        return Some(0);
+    }
+    if n == 1 {
+        return Some(1);
+    }
+    let mut a: u64 = 0;
+    let mut b: u64 = 1;
+    for _ in 2..=n {
+        let temp = a.checked_add(b)?;
+        a = b;
+        b = temp;
+    }
+    Some(b)
+}
+
+/// Returns the nth Fibonacci number in `O(log n)` time using fast doubling.
+///
+/// Panics on overflow past `n = 93`, same limit as [`fibonacci`].
+pub fn fibonacci_fast(n: u64) -> u64 {
+    fibonacci_fast_checked(n).expect("fibonacci_fast overflowed u64")
+}
+
+/// Checked variant of [`fibonacci_fast`] that returns `None` on overflow.
+fn fibonacci_fast_checked(n: u64) -> Option<u64> {
+    let mut a: u64 = 0;
+    let mut b: u64 = 1;
+    let bits = u64::BITS - n.leading_zeros();
+    for i in (0..bits).rev() {
+        let last = i == 0;
+        let two_b = b.checked_mul(2)?.checked_sub(a)?;
+        let c = a.checked_mul(two_b)?;
+        if (n >> i) & 1 == 0 {
+            if last {
+                // Only `a` (the final answer) is needed; don't require the
+                // discarded `b = F(n + 1)` to fit too.
+                a = c;
+            } else {
+                let d = a.checked_mul(a)?.checked_add(b.checked_mul(b)?)?;
+                a = c;
+                b = d;
+            }
+        } else {
+            let d = a.checked_mul(a)?.checked_add(b.checked_mul(b)?)?;
+            if last {
+                a = d;
+            } else {
+                b = c.checked_add(d)?;
+                a = d;
+            }
+        }
+    }
+    Some(a)
+}
+
+/// Lazily yields the Fibonacci sequence: 0, 1, 1, 2, 3, 5, …
+///
+/// Stops (returns `None`) once the next value would overflow `u64`.
+pub struct FibonacciIter {
+    a: u64,
+    b: Option<u64>,
+    exhausted: bool,
+}
+
+impl Iterator for FibonacciIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.exhausted {
+            return None;
+        }
+        let current = self.a;
+        match self.b {
+            Some(b) => {
+                let next_b = self.a.checked_add(b);
+                self.a = b;
+                self.b = next_b;
+            }
+            None => self.exhausted = true,
+        }
+        Some(current)
+    }
+}
+
+/// Returns an iterator over the Fibonacci sequence, computed incrementally.
+pub fn fibonacci_seq() -> FibonacciIter {
+    FibonacciIter {
+        a: 0,
+        b: Some(1),
+        exhausted: false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::num::Wrapping;
 
     #[test]
     fn test_clamp_within_range() {
@@ -38,7 +200,32 @@ mod tests {
 
     #[test]
     fn test_clamp_at_max() {
-        assert_eq!(clamp(10, 1, 10), 10);  // Fails: >= returns 10 but boundary is wrong
+        assert_eq!(clamp(10, 1, 10), 10);
+    }
+
+    #[test]
+    fn test_clamp_ext_method() {
+        assert_eq!(5.clamped(1, 10), 5);
+        assert_eq!("b".clamped("a", "c"), "b");
+    }
+
+    #[test]
+    #[should_panic(expected = "min must be <= max")]
+    fn test_clamp_panics_on_inverted_bounds() {
+        clamp(5, 10, 1);
+    }
+
+    #[test]
+    fn test_clamp_range() {
+        assert_eq!(clamp_range(15, 1..=10), 10);
+        assert_eq!(clamp_range(5, 1..=10), 5);
+        assert_eq!(clamp_range(0, 1..=10), 1);
+    }
+
+    #[test]
+    fn test_clamp_wrapping() {
+        assert_eq!(clamp(Wrapping(15), Wrapping(1), Wrapping(10)), Wrapping(10));
+        assert_eq!(clamp_range(Wrapping(0i64), Wrapping(1)..=Wrapping(10)), Wrapping(1));
     }
 
     #[test]
@@ -51,6 +238,52 @@ mod tests {
         assert_eq!(clamp(15, 1, 10), 10);
     }
 
+    #[test]
+    fn test_clamp_f64_within_range() {
+        assert_eq!(clamp_f64(5.0, 1.0, 10.0), 5.0);
+    }
+
+    #[test]
+    fn test_clamp_f64_nan_propagates() {
+        assert!(clamp_f64(f64::NAN, 1.0, 10.0).is_nan());
+    }
+
+    #[test]
+    fn test_clamp_f64_infinities() {
+        assert_eq!(clamp_f64(f64::NEG_INFINITY, 1.0, 10.0), 1.0);
+        assert_eq!(clamp_f64(f64::INFINITY, 1.0, 10.0), 10.0);
+        assert_eq!(clamp_f64(5.0, f64::NEG_INFINITY, f64::INFINITY), 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "min must not be NaN")]
+    fn test_clamp_f64_panics_on_nan_bound() {
+        clamp_f64(5.0, f64::NAN, 10.0);
+    }
+
+    #[test]
+    fn test_clamp_f32_within_range() {
+        assert_eq!(clamp_f32(5.0, 1.0, 10.0), 5.0);
+    }
+
+    #[test]
+    fn test_clamp_f32_nan_propagates() {
+        assert!(clamp_f32(f32::NAN, 1.0, 10.0).is_nan());
+    }
+
+    #[test]
+    fn test_clamp_f32_infinities() {
+        assert_eq!(clamp_f32(f32::NEG_INFINITY, 1.0, 10.0), 1.0);
+        assert_eq!(clamp_f32(f32::INFINITY, 1.0, 10.0), 10.0);
+        assert_eq!(clamp_f32(5.0, f32::NEG_INFINITY, f32::INFINITY), 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "min must not be NaN")]
+    fn test_clamp_f32_panics_on_nan_bound() {
+        clamp_f32(5.0, f32::NAN, 10.0);
+    }
+
     #[test]
     fn test_fibonacci_base() {
         assert_eq!(fibonacci(0), 0);
@@ -61,4 +294,52 @@ mod tests {
     fn test_fibonacci_sequence() {
         assert_eq!(fibonacci(10), 55);
     }
+
+    #[test]
+    fn test_checked_fibonacci_matches_fibonacci() {
+        for n in 0..=93 {
+            assert_eq!(checked_fibonacci(n), Some(fibonacci(n)));
+        }
+    }
+
+    #[test]
+    fn test_checked_fibonacci_overflow() {
+        assert_eq!(checked_fibonacci(94), None);
+    }
+
+    #[test]
+    fn test_fibonacci_fast_matches_iterative() {
+        for n in 0..=93u64 {
+            assert_eq!(fibonacci_fast(n), fibonacci(n as u32));
+        }
+    }
+
+    #[test]
+    fn test_fibonacci_fast_boundary_n93() {
+        assert_eq!(fibonacci_fast(93), checked_fibonacci(93).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "fibonacci_fast overflowed u64")]
+    fn test_fibonacci_fast_panics_on_overflow() {
+        fibonacci_fast(94);
+    }
+
+    #[test]
+    fn test_fibonacci_seq_take() {
+        let got: Vec<u64> = fibonacci_seq().take(10).collect();
+        assert_eq!(got, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    }
+
+    #[test]
+    fn test_fibonacci_seq_matches_fibonacci() {
+        for (n, value) in fibonacci_seq().take(94).enumerate() {
+            assert_eq!(value, fibonacci(n as u32));
+        }
+    }
+
+    #[test]
+    fn test_fibonacci_seq_stops_before_overflow() {
+        assert_eq!(fibonacci_seq().count(), 94);
+    }
 }